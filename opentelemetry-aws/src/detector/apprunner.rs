@@ -0,0 +1,115 @@
+//! AWS App Runner resource detector
+//!
+//! Detect the App Runner service from the environment variables of the running instance.
+use crate::detector::keys::AWS_APPRUNNER_SERVICE_ID;
+use crate::detector::AWS_REGION_ENV_VAR;
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use opentelemetry_semantic_conventions::resource::{CLOUD_PLATFORM, CLOUD_PROVIDER, CLOUD_REGION};
+use std::env;
+use std::time::Duration;
+
+const AWS_APP_RUNNER_SERVICE_ID_ENV_VAR: &str = "AWS_APP_RUNNER_SERVICE_ID";
+
+/// Detect AWS App Runner resource attributes.
+///
+/// Populates `cloud.provider`, `cloud.platform`, `cloud.region` and `aws.apprunner.service.id` from
+/// the `AWS_APP_RUNNER_SERVICE_ID` and `AWS_REGION` environment variables. Returns an empty
+/// resource when `AWS_APP_RUNNER_SERVICE_ID` isn't set.
+///
+/// The service ID is shared by every instance of the service, so it isn't used as
+/// `service.instance.id`.
+pub struct AppRunnerResourceDetector;
+
+impl ResourceDetector for AppRunnerResourceDetector {
+    fn detect(&self, _timeout: Duration) -> Resource {
+        let service_id = env::var(AWS_APP_RUNNER_SERVICE_ID_ENV_VAR).unwrap_or_default();
+        if service_id.is_empty() {
+            return Resource::empty();
+        }
+
+        let mut attributes = vec![
+            KeyValue::new(CLOUD_PROVIDER, "aws"),
+            KeyValue::new(CLOUD_PLATFORM, "aws_app_runner"),
+            KeyValue::new(AWS_APPRUNNER_SERVICE_ID, service_id),
+        ];
+
+        if let Some(region) = env::var(AWS_REGION_ENV_VAR)
+            .ok()
+            .filter(|region| !region.is_empty())
+        {
+            attributes.push(KeyValue::new(CLOUD_REGION, region));
+        }
+
+        Resource::new(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{Key, Value};
+
+    #[test]
+    fn test_apprunner_resource_detector() {
+        temp_env::with_vars(
+            [
+                (
+                    AWS_APP_RUNNER_SERVICE_ID_ENV_VAR,
+                    Some("8fe1e10304f84fd2b0df550fe98a71fa"),
+                ),
+                (AWS_REGION_ENV_VAR, Some("eu-west-1")),
+            ],
+            || {
+                let resource = AppRunnerResourceDetector.detect(Duration::from_secs(0));
+
+                assert_eq!(resource.len(), 4);
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_PROVIDER)),
+                    Some(Value::from("aws"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_PLATFORM)),
+                    Some(Value::from("aws_app_runner"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_REGION)),
+                    Some(Value::from("eu-west-1"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(AWS_APPRUNNER_SERVICE_ID)),
+                    Some(Value::from("8fe1e10304f84fd2b0df550fe98a71fa"))
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_apprunner_resource_detector_empty_region() {
+        temp_env::with_vars(
+            [
+                (
+                    AWS_APP_RUNNER_SERVICE_ID_ENV_VAR,
+                    Some("8fe1e10304f84fd2b0df550fe98a71fa"),
+                ),
+                (AWS_REGION_ENV_VAR, Some("")),
+            ],
+            || {
+                let resource = AppRunnerResourceDetector.detect(Duration::from_secs(0));
+
+                assert_eq!(resource.len(), 3);
+                assert_eq!(resource.get(Key::from_static_str(CLOUD_REGION)), None);
+            },
+        );
+    }
+
+    #[test]
+    fn test_apprunner_resource_detector_not_on_apprunner() {
+        temp_env::with_var_unset(AWS_APP_RUNNER_SERVICE_ID_ENV_VAR, || {
+            assert!(AppRunnerResourceDetector
+                .detect(Duration::from_secs(0))
+                .is_empty());
+        });
+    }
+}
@@ -0,0 +1,154 @@
+//! AWS Batch resource detector
+//!
+//! Detect the AWS Batch job from the environment variables of the running container.
+use crate::detector::keys::{
+    AWS_BATCH_COMPUTE_ENVIRONMENT, AWS_BATCH_JOB_ATTEMPT, AWS_BATCH_JOB_ID,
+};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use opentelemetry_semantic_conventions::resource::CLOUD_PROVIDER;
+use std::env;
+use std::time::Duration;
+
+const AWS_BATCH_JOB_ID_ENV_VAR: &str = "AWS_BATCH_JOB_ID";
+const AWS_BATCH_JOB_ATTEMPT_ENV_VAR: &str = "AWS_BATCH_JOB_ATTEMPT";
+const AWS_BATCH_CE_NAME_ENV_VAR: &str = "AWS_BATCH_CE_NAME";
+
+/// Detect AWS Batch job resource attributes.
+///
+/// Populates `cloud.provider`, `aws.batch.job.id`, `aws.batch.job.attempt` and
+/// `aws.batch.compute_environment` from the [environment variables][batch-env] set by AWS Batch.
+/// Returns an empty resource when `AWS_BATCH_JOB_ID` isn't set.
+///
+/// [batch-env]: https://docs.aws.amazon.com/batch/latest/userguide/job_env_vars.html
+pub struct BatchResourceDetector;
+
+impl ResourceDetector for BatchResourceDetector {
+    fn detect(&self, _timeout: Duration) -> Resource {
+        let job_id = env::var(AWS_BATCH_JOB_ID_ENV_VAR).unwrap_or_default();
+        if job_id.is_empty() {
+            return Resource::empty();
+        }
+
+        let mut attributes = vec![
+            KeyValue::new(CLOUD_PROVIDER, "aws"),
+            KeyValue::new(AWS_BATCH_JOB_ID, job_id),
+        ];
+
+        if let Some(attempt) = env::var(AWS_BATCH_JOB_ATTEMPT_ENV_VAR)
+            .ok()
+            .and_then(|attempt| attempt.parse::<i64>().ok())
+        {
+            attributes.push(KeyValue::new(AWS_BATCH_JOB_ATTEMPT, attempt));
+        }
+
+        if let Some(compute_environment) = env::var(AWS_BATCH_CE_NAME_ENV_VAR)
+            .ok()
+            .filter(|name| !name.is_empty())
+        {
+            attributes.push(KeyValue::new(
+                AWS_BATCH_COMPUTE_ENVIRONMENT,
+                compute_environment,
+            ));
+        }
+
+        Resource::new(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{Key, Value};
+
+    #[test]
+    fn test_batch_resource_detector() {
+        temp_env::with_vars(
+            [
+                (
+                    AWS_BATCH_JOB_ID_ENV_VAR,
+                    Some("a4d6c728-8ee8-4053-9b0c-2e1dc1c4e2c4"),
+                ),
+                (AWS_BATCH_JOB_ATTEMPT_ENV_VAR, Some("2")),
+                (AWS_BATCH_CE_NAME_ENV_VAR, Some("my-compute-environment")),
+            ],
+            || {
+                let resource = BatchResourceDetector.detect(Duration::from_secs(0));
+
+                assert_eq!(resource.len(), 4);
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_PROVIDER)),
+                    Some(Value::from("aws"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(AWS_BATCH_JOB_ID)),
+                    Some(Value::from("a4d6c728-8ee8-4053-9b0c-2e1dc1c4e2c4"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(AWS_BATCH_JOB_ATTEMPT)),
+                    Some(Value::from(2))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(AWS_BATCH_COMPUTE_ENVIRONMENT)),
+                    Some(Value::from("my-compute-environment"))
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_batch_resource_detector_job_id_only() {
+        temp_env::with_vars(
+            [
+                (
+                    AWS_BATCH_JOB_ID_ENV_VAR,
+                    Some("a4d6c728-8ee8-4053-9b0c-2e1dc1c4e2c4"),
+                ),
+                (AWS_BATCH_JOB_ATTEMPT_ENV_VAR, None),
+                (AWS_BATCH_CE_NAME_ENV_VAR, None),
+            ],
+            || {
+                let resource = BatchResourceDetector.detect(Duration::from_secs(0));
+
+                assert_eq!(resource.len(), 2);
+                assert_eq!(
+                    resource.get(Key::from_static_str(AWS_BATCH_JOB_ATTEMPT)),
+                    None
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_batch_resource_detector_empty_compute_environment() {
+        temp_env::with_vars(
+            [
+                (
+                    AWS_BATCH_JOB_ID_ENV_VAR,
+                    Some("a4d6c728-8ee8-4053-9b0c-2e1dc1c4e2c4"),
+                ),
+                (AWS_BATCH_JOB_ATTEMPT_ENV_VAR, None),
+                (AWS_BATCH_CE_NAME_ENV_VAR, Some("")),
+            ],
+            || {
+                let resource = BatchResourceDetector.detect(Duration::from_secs(0));
+
+                assert_eq!(resource.len(), 2);
+                assert_eq!(
+                    resource.get(Key::from_static_str(AWS_BATCH_COMPUTE_ENVIRONMENT)),
+                    None
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_batch_resource_detector_not_on_batch() {
+        temp_env::with_var_unset(AWS_BATCH_JOB_ID_ENV_VAR, || {
+            assert!(BatchResourceDetector
+                .detect(Duration::from_secs(0))
+                .is_empty());
+        });
+    }
+}
@@ -0,0 +1,179 @@
+//! Caching resource detector adapter
+//!
+//! Memoize the result of a resource detector so it doesn't run again.
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Cache the resource detected by a wrapped [`ResourceDetector`].
+///
+/// The first non-empty resource returned by the wrapped detector is served by every following
+/// [`detect`] until the TTL expires, without running the wrapped detector again. The TTL is unset
+/// by default, keeping the resource forever. Empty resources are treated as failed detections and
+/// aren't cached, so the next call runs the wrapped detector again.
+///
+/// The cache isn't locked while the wrapped detector runs, so each caller waits at most its own
+/// `timeout`. Callers racing on an empty cache may all run the wrapped detector, the last success
+/// being cached.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry_aws::detector::CachingDetector;
+/// use opentelemetry_sdk::resource::{ResourceDetector, SdkProvidedResourceDetector};
+/// use std::time::Duration;
+///
+/// let detector =
+///     CachingDetector::new(SdkProvidedResourceDetector).with_ttl(Duration::from_secs(3600));
+/// let resource = detector.detect(Duration::from_secs(1));
+/// ```
+///
+/// [`detect`]: ResourceDetector::detect
+#[derive(Debug)]
+pub struct CachingDetector<D> {
+    detector: D,
+    ttl: Option<Duration>,
+    cached: Mutex<Option<(Instant, Resource)>>,
+}
+
+impl<D: ResourceDetector> CachingDetector<D> {
+    /// Wraps `detector`, caching its first successful result forever.
+    pub fn new(detector: D) -> Self {
+        CachingDetector {
+            detector,
+            ttl: None,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Expire the cached resource `ttl` after it was detected.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    fn lock_cached(&self) -> MutexGuard<'_, Option<(Instant, Resource)>> {
+        self.cached
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<D: ResourceDetector> ResourceDetector for CachingDetector<D> {
+    fn detect(&self, timeout: Duration) -> Resource {
+        if let Some((detected_at, resource)) = self.lock_cached().as_ref() {
+            if self.ttl.map_or(true, |ttl| detected_at.elapsed() < ttl) {
+                return resource.clone();
+            }
+        }
+
+        let resource = self.detector.detect(timeout);
+        if !resource.is_empty() {
+            *self.lock_cached() = Some((Instant::now(), resource.clone()));
+        }
+
+        resource
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::KeyValue;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread::sleep;
+
+    /// Fails the first `failures` detections, then succeeds.
+    struct TestDetector {
+        failures: usize,
+        calls: AtomicUsize,
+    }
+
+    impl TestDetector {
+        fn new(failures: usize) -> Self {
+            TestDetector {
+                failures,
+                calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl ResourceDetector for &TestDetector {
+        fn detect(&self, _timeout: Duration) -> Resource {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                Resource::empty()
+            } else {
+                Resource::new(vec![KeyValue::new("cloud.provider", "aws")])
+            }
+        }
+    }
+
+    #[test]
+    fn test_caching_detector() {
+        let inner = TestDetector::new(0);
+        let detector = CachingDetector::new(&inner);
+
+        let resource = detector.detect(Duration::from_secs(0));
+        assert_eq!(resource.len(), 1);
+        assert_eq!(detector.detect(Duration::from_secs(0)), resource);
+        assert_eq!(detector.detect(Duration::from_secs(0)), resource);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_caching_detector_failure_not_cached() {
+        let inner = TestDetector::new(1);
+        let detector = CachingDetector::new(&inner);
+
+        assert!(detector.detect(Duration::from_secs(0)).is_empty());
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_caching_detector_ttl() {
+        let inner = TestDetector::new(0);
+        let detector = CachingDetector::new(&inner).with_ttl(Duration::from_secs(3600));
+
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_caching_detector_ttl_expired() {
+        let inner = TestDetector::new(0);
+        let detector = CachingDetector::new(&inner).with_ttl(Duration::from_millis(10));
+
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        sleep(Duration::from_millis(50));
+
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    struct SlowDetector;
+
+    impl ResourceDetector for SlowDetector {
+        fn detect(&self, timeout: Duration) -> Resource {
+            sleep(timeout);
+            Resource::new(vec![KeyValue::new("cloud.provider", "aws")])
+        }
+    }
+
+    #[test]
+    fn test_caching_detector_not_locked_during_detection() {
+        let detector = CachingDetector::new(SlowDetector);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| detector.detect(Duration::from_millis(500)));
+            sleep(Duration::from_millis(50));
+
+            let start = Instant::now();
+            assert!(!detector.detect(Duration::from_millis(0)).is_empty());
+            assert!(start.elapsed() < Duration::from_millis(400));
+        });
+    }
+}
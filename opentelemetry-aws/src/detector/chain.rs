@@ -0,0 +1,199 @@
+//! Detector chain
+//!
+//! Run several resource detectors within a single timeout budget.
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Run [`ResourceDetector`]s in order and merge their results.
+///
+/// Detectors run in the order they were added, and attributes from later detectors win on
+/// conflict. The timeout given to [`detect`] is a budget for the whole chain: each detector
+/// receives an even share of what is left, so time a detector doesn't use rolls over to the next
+/// ones. Once a non-zero budget is spent, the remaining detectors no longer run. A zero timeout is
+/// passed through to every detector, as [`Resource::from_detectors`] does.
+///
+/// Detectors must be `Send + Sync` so that the chain can be shared across threads.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry_aws::detector::DetectorChain;
+/// use opentelemetry_sdk::resource::{
+///     ResourceDetector, SdkProvidedResourceDetector, TelemetryResourceDetector,
+/// };
+/// use std::time::Duration;
+///
+/// let chain = DetectorChain::new()
+///     .with_detector(Box::new(TelemetryResourceDetector))
+///     .with_detector(Box::new(SdkProvidedResourceDetector));
+/// let resource = chain.detect(Duration::from_secs(2));
+/// ```
+///
+/// [`detect`]: ResourceDetector::detect
+#[derive(Default)]
+pub struct DetectorChain {
+    detectors: Vec<Box<dyn ResourceDetector + Send + Sync>>,
+}
+
+impl fmt::Debug for DetectorChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DetectorChain")
+            .field("detectors", &self.detectors.len())
+            .finish()
+    }
+}
+
+impl DetectorChain {
+    /// Creates an empty detector chain.
+    pub fn new() -> Self {
+        DetectorChain::default()
+    }
+
+    /// Append `detector` to the chain.
+    pub fn with_detector(mut self, detector: Box<dyn ResourceDetector + Send + Sync>) -> Self {
+        self.detectors.push(detector);
+        self
+    }
+}
+
+impl From<Vec<Box<dyn ResourceDetector + Send + Sync>>> for DetectorChain {
+    fn from(detectors: Vec<Box<dyn ResourceDetector + Send + Sync>>) -> Self {
+        DetectorChain { detectors }
+    }
+}
+
+impl ResourceDetector for DetectorChain {
+    fn detect(&self, timeout: Duration) -> Resource {
+        let deadline = Instant::now() + timeout;
+        let mut resource = Resource::empty();
+
+        for (index, detector) in self.detectors.iter().enumerate() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if !timeout.is_zero() && remaining.is_zero() {
+                break;
+            }
+
+            let share = remaining / (self.detectors.len() - index) as u32;
+            resource = resource.merge(&detector.detect(share));
+        }
+
+        resource
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{Key, KeyValue, Value};
+    use std::sync::{Arc, Mutex};
+
+    struct TestDetector {
+        attributes: Vec<KeyValue>,
+        timeouts: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl ResourceDetector for TestDetector {
+        fn detect(&self, timeout: Duration) -> Resource {
+            self.timeouts.lock().unwrap().push(timeout);
+            Resource::new(self.attributes.clone())
+        }
+    }
+
+    fn test_detector(
+        attributes: Vec<KeyValue>,
+        timeouts: &Arc<Mutex<Vec<Duration>>>,
+    ) -> Box<dyn ResourceDetector + Send + Sync> {
+        Box::new(TestDetector {
+            attributes,
+            timeouts: timeouts.clone(),
+        })
+    }
+
+    #[test]
+    fn test_detector_chain_merge_order() {
+        let timeouts = Arc::new(Mutex::new(Vec::new()));
+        let chain = DetectorChain::new()
+            .with_detector(test_detector(
+                vec![
+                    KeyValue::new("cloud.provider", "aws"),
+                    KeyValue::new("cloud.region", "eu-west-1"),
+                ],
+                &timeouts,
+            ))
+            .with_detector(test_detector(
+                vec![
+                    KeyValue::new("cloud.region", "us-east-1"),
+                    KeyValue::new("host.id", "i-1234567890abcdef0"),
+                ],
+                &timeouts,
+            ));
+
+        let resource = chain.detect(Duration::from_secs(10));
+
+        assert_eq!(resource.len(), 3);
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.provider")),
+            Some(Value::from("aws"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.region")),
+            Some(Value::from("us-east-1"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("host.id")),
+            Some(Value::from("i-1234567890abcdef0"))
+        );
+
+        let timeouts = timeouts.lock().unwrap();
+        assert_eq!(timeouts.len(), 2);
+        assert!(timeouts[0] <= Duration::from_secs(5));
+        assert!(timeouts[0] > Duration::from_secs(4));
+        assert!(timeouts[1] <= Duration::from_secs(10));
+        assert!(timeouts[1] > timeouts[0]);
+    }
+
+    #[test]
+    fn test_detector_chain_zero_timeout() {
+        let timeouts = Arc::new(Mutex::new(Vec::new()));
+        let chain = DetectorChain::new()
+            .with_detector(test_detector(
+                vec![KeyValue::new("cloud.provider", "aws")],
+                &timeouts,
+            ))
+            .with_detector(test_detector(
+                vec![KeyValue::new("cloud.region", "eu-west-1")],
+                &timeouts,
+            ));
+
+        assert_eq!(chain.detect(Duration::from_secs(0)).len(), 2);
+        assert_eq!(*timeouts.lock().unwrap(), vec![Duration::ZERO; 2]);
+    }
+
+    struct SlowDetector;
+
+    impl ResourceDetector for SlowDetector {
+        fn detect(&self, timeout: Duration) -> Resource {
+            std::thread::sleep(timeout + Duration::from_millis(50));
+            Resource::new(vec![KeyValue::new("cloud.provider", "aws")])
+        }
+    }
+
+    #[test]
+    fn test_detector_chain_budget_spent() {
+        let timeouts = Arc::new(Mutex::new(Vec::new()));
+        let chain = DetectorChain::new()
+            .with_detector(Box::new(SlowDetector))
+            .with_detector(test_detector(
+                vec![KeyValue::new("cloud.region", "eu-west-1")],
+                &timeouts,
+            ));
+
+        let resource = chain.detect(Duration::from_millis(100));
+
+        assert_eq!(resource.len(), 1);
+        assert_eq!(resource.get(Key::from_static_str("cloud.region")), None);
+        assert!(timeouts.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+//! Deadline resource detector adapter
+//!
+//! Bound the time spent by a resource detector with a deadline shared across detectors.
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::time::{Duration, Instant};
+
+/// Apply a deadline to a wrapped [`ResourceDetector`].
+///
+/// On [`detect`], the wrapped detector receives the smaller of the given timeout and the time left
+/// until the deadline. Once the deadline has passed, an empty resource is returned without running
+/// the wrapped detector.
+///
+/// Sharing the same `Instant` between several wrapped detectors gives them a single global budget.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry_aws::detector::WithDeadline;
+/// use opentelemetry_sdk::resource::{SdkProvidedResourceDetector, TelemetryResourceDetector};
+/// use opentelemetry_sdk::Resource;
+/// use std::time::{Duration, Instant};
+///
+/// let deadline = Instant::now() + Duration::from_secs(2);
+/// let resource = Resource::from_detectors(
+///     Duration::from_secs(1),
+///     vec![
+///         Box::new(WithDeadline::new(SdkProvidedResourceDetector, deadline)),
+///         Box::new(WithDeadline::new(TelemetryResourceDetector, deadline)),
+///     ],
+/// );
+/// ```
+///
+/// [`detect`]: ResourceDetector::detect
+#[derive(Debug)]
+pub struct WithDeadline<D> {
+    detector: D,
+    deadline: Instant,
+}
+
+impl<D: ResourceDetector> WithDeadline<D> {
+    /// Wraps `detector` so that it doesn't run past `deadline`.
+    pub fn new(detector: D, deadline: Instant) -> Self {
+        WithDeadline { detector, deadline }
+    }
+}
+
+impl<D: ResourceDetector> ResourceDetector for WithDeadline<D> {
+    fn detect(&self, timeout: Duration) -> Resource {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Resource::empty();
+        }
+
+        self.detector.detect(timeout.min(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::KeyValue;
+    use std::sync::Mutex;
+    use std::thread::sleep;
+
+    #[derive(Default)]
+    struct TestDetector {
+        timeouts: Mutex<Vec<Duration>>,
+    }
+
+    impl ResourceDetector for &TestDetector {
+        fn detect(&self, timeout: Duration) -> Resource {
+            self.timeouts.lock().unwrap().push(timeout);
+            Resource::new(vec![KeyValue::new("detected", true)])
+        }
+    }
+
+    #[test]
+    fn test_with_deadline_shrinks_timeout() {
+        let inner = TestDetector::default();
+        let detector = WithDeadline::new(&inner, Instant::now() + Duration::from_secs(60));
+
+        assert_eq!(detector.detect(Duration::from_secs(1)).len(), 1);
+        assert!(!detector.detect(Duration::from_secs(120)).is_empty());
+        sleep(Duration::from_millis(10));
+        assert!(!detector.detect(Duration::from_secs(120)).is_empty());
+
+        let timeouts = inner.timeouts.lock().unwrap();
+        assert_eq!(timeouts[0], Duration::from_secs(1));
+        assert!(timeouts[1] <= Duration::from_secs(60));
+        assert!(timeouts[2] < timeouts[1]);
+    }
+
+    #[test]
+    fn test_with_deadline_passed() {
+        let inner = TestDetector::default();
+        let detector = WithDeadline::new(&inner, Instant::now());
+
+        sleep(Duration::from_millis(1));
+
+        assert!(detector.detect(Duration::from_secs(1)).is_empty());
+        assert!(inner.timeouts.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+//! AWS environment resource detector
+//!
+//! Detect the AWS region from the environment variables used by the AWS SDKs.
+use crate::detector::AWS_REGION_ENV_VAR;
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use opentelemetry_semantic_conventions::resource::{CLOUD_PROVIDER, CLOUD_REGION};
+use std::env;
+use std::time::Duration;
+
+const AWS_DEFAULT_REGION_ENV_VAR: &str = "AWS_DEFAULT_REGION";
+
+/// Detect the AWS region from the `AWS_REGION` or `AWS_DEFAULT_REGION` environment variables.
+///
+/// Populates `cloud.provider` and `cloud.region` when either variable is set to a non-empty value,
+/// `AWS_REGION` taking precedence. Returns an empty resource otherwise.
+///
+/// This detector doesn't make any network call, so it can act as a fallback for detectors that
+/// query AWS: place it before them in [`Resource::from_detectors`] and the region they detect
+/// wins whenever they succeed.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry_aws::detector::EnvResourceDetector;
+/// use opentelemetry_sdk::Resource;
+/// use std::time::Duration;
+///
+/// let resource = Resource::from_detectors(Duration::from_secs(0), vec![Box::new(EnvResourceDetector)]);
+/// ```
+pub struct EnvResourceDetector;
+
+impl ResourceDetector for EnvResourceDetector {
+    fn detect(&self, _timeout: Duration) -> Resource {
+        let region = [AWS_REGION_ENV_VAR, AWS_DEFAULT_REGION_ENV_VAR]
+            .into_iter()
+            .find_map(|name| env::var(name).ok().filter(|region| !region.is_empty()));
+
+        let Some(region) = region else {
+            return Resource::empty();
+        };
+
+        Resource::new(vec![
+            KeyValue::new(CLOUD_PROVIDER, "aws"),
+            KeyValue::new(CLOUD_REGION, region),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{Key, Value};
+
+    struct TestDetector(Resource);
+
+    impl ResourceDetector for TestDetector {
+        fn detect(&self, _timeout: Duration) -> Resource {
+            self.0.clone()
+        }
+    }
+
+    fn detect_with(resource: Resource) -> Resource {
+        Resource::from_detectors(
+            Duration::from_secs(0),
+            vec![
+                Box::new(EnvResourceDetector),
+                Box::new(TestDetector(resource)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_env_resource_detector() {
+        temp_env::with_vars(
+            [
+                (AWS_REGION_ENV_VAR, Some("eu-west-1")),
+                (AWS_DEFAULT_REGION_ENV_VAR, Some("eu-west-3")),
+            ],
+            || {
+                let resource = EnvResourceDetector.detect(Duration::from_secs(0));
+                assert_eq!(resource.len(), 2);
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_PROVIDER)),
+                    Some(Value::from("aws"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_REGION)),
+                    Some(Value::from("eu-west-1"))
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_env_resource_detector_default_region() {
+        temp_env::with_vars(
+            [
+                (AWS_REGION_ENV_VAR, None),
+                (AWS_DEFAULT_REGION_ENV_VAR, Some("eu-west-3")),
+            ],
+            || {
+                let resource = EnvResourceDetector.detect(Duration::from_secs(0));
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_REGION)),
+                    Some(Value::from("eu-west-3"))
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_env_resource_detector_empty_region() {
+        temp_env::with_vars(
+            [
+                (AWS_REGION_ENV_VAR, Some("")),
+                (AWS_DEFAULT_REGION_ENV_VAR, Some("eu-west-3")),
+            ],
+            || {
+                let resource = EnvResourceDetector.detect(Duration::from_secs(0));
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_REGION)),
+                    Some(Value::from("eu-west-3"))
+                );
+            },
+        );
+
+        temp_env::with_vars(
+            [
+                (AWS_REGION_ENV_VAR, Some("")),
+                (AWS_DEFAULT_REGION_ENV_VAR, Some("")),
+            ],
+            || {
+                assert!(EnvResourceDetector
+                    .detect(Duration::from_secs(0))
+                    .is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn test_env_resource_detector_fallback() {
+        temp_env::with_var(AWS_REGION_ENV_VAR, Some("eu-west-1"), || {
+            // the metadata service succeeded, its region wins
+            let resource = detect_with(Resource::new(vec![
+                KeyValue::new(CLOUD_PROVIDER, "aws"),
+                KeyValue::new(CLOUD_REGION, "us-east-1"),
+            ]));
+            assert_eq!(
+                resource.get(Key::from_static_str(CLOUD_REGION)),
+                Some(Value::from("us-east-1"))
+            );
+
+            // the metadata service failed, the region falls back to the environment
+            let resource = detect_with(Resource::empty());
+            assert_eq!(
+                resource.get(Key::from_static_str(CLOUD_REGION)),
+                Some(Value::from("eu-west-1"))
+            );
+        });
+    }
+
+    #[test]
+    fn test_env_resource_detector_unset() {
+        temp_env::with_vars_unset([AWS_REGION_ENV_VAR, AWS_DEFAULT_REGION_ENV_VAR], || {
+            assert!(EnvResourceDetector
+                .detect(Duration::from_secs(0))
+                .is_empty());
+            assert!(detect_with(Resource::empty()).is_empty());
+        });
+    }
+}
@@ -0,0 +1,133 @@
+//! Attribute filtering resource detector adapter
+//!
+//! Drop attributes emitted by a resource detector.
+use opentelemetry::{Key, KeyValue};
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Filter the attributes of a wrapped [`ResourceDetector`] by key.
+///
+/// Excluded keys are removed from the detected resource. When at least one key is included, only
+/// included keys are kept, so the included keys act as an allowlist. This helps keep sensitive
+/// attributes such as `cloud.account.id` or `host.id` out of the exported telemetry.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::KeyValue;
+/// use opentelemetry_aws::detector::{AttributeFilterDetector, StaticResourceDetector};
+/// use opentelemetry_sdk::resource::ResourceDetector;
+/// use std::time::Duration;
+///
+/// let detector = AttributeFilterDetector::new(StaticResourceDetector::new(vec![
+///     KeyValue::new("cloud.provider", "aws"),
+///     KeyValue::new("cloud.account.id", "123456789012"),
+/// ]))
+/// .with_excluded_key("cloud.account.id");
+/// let resource = detector.detect(Duration::from_secs(0));
+/// ```
+#[derive(Debug)]
+pub struct AttributeFilterDetector<D> {
+    detector: D,
+    included: HashSet<Key>,
+    excluded: HashSet<Key>,
+}
+
+impl<D: ResourceDetector> AttributeFilterDetector<D> {
+    /// Wraps `detector`, keeping all of its attributes until keys are included or excluded.
+    pub fn new(detector: D) -> Self {
+        AttributeFilterDetector {
+            detector,
+            included: HashSet::new(),
+            excluded: HashSet::new(),
+        }
+    }
+
+    /// Keep only the included attribute keys, `key` among them.
+    pub fn with_included_key<K: Into<Key>>(mut self, key: K) -> Self {
+        self.included.insert(key.into());
+        self
+    }
+
+    /// Remove the `key` attribute.
+    pub fn with_excluded_key<K: Into<Key>>(mut self, key: K) -> Self {
+        self.excluded.insert(key.into());
+        self
+    }
+
+    fn is_kept(&self, key: &Key) -> bool {
+        (self.included.is_empty() || self.included.contains(key)) && !self.excluded.contains(key)
+    }
+}
+
+impl<D: ResourceDetector> ResourceDetector for AttributeFilterDetector<D> {
+    fn detect(&self, timeout: Duration) -> Resource {
+        let resource = self.detector.detect(timeout);
+        if self.included.is_empty() && self.excluded.is_empty() {
+            return resource;
+        }
+
+        let attributes = resource
+            .iter()
+            .filter(|(key, _)| self.is_kept(key))
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .collect::<Vec<_>>();
+
+        match resource.schema_url() {
+            Some(schema_url) => Resource::from_schema_url(attributes, schema_url.to_owned()),
+            None => Resource::new(attributes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::StaticResourceDetector;
+    use opentelemetry::Value;
+
+    fn static_detector() -> StaticResourceDetector {
+        StaticResourceDetector::new(vec![
+            KeyValue::new("cloud.provider", "aws"),
+            KeyValue::new("cloud.account.id", "123456789012"),
+            KeyValue::new("host.id", "i-1234567890abcdef0"),
+        ])
+    }
+
+    #[test]
+    fn test_attribute_filter_detector_excluded_keys() {
+        let detector =
+            AttributeFilterDetector::new(static_detector()).with_excluded_key("cloud.account.id");
+
+        let resource = detector.detect(Duration::from_secs(0));
+
+        assert_eq!(resource.len(), 2);
+        assert_eq!(resource.get(Key::from_static_str("cloud.account.id")), None);
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.provider")),
+            Some(Value::from("aws"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("host.id")),
+            Some(Value::from("i-1234567890abcdef0"))
+        );
+    }
+
+    #[test]
+    fn test_attribute_filter_detector_included_keys() {
+        let detector = AttributeFilterDetector::new(static_detector())
+            .with_included_key("cloud.provider")
+            .with_included_key("host.id")
+            .with_excluded_key("host.id");
+
+        let resource = detector.detect(Duration::from_secs(0));
+
+        assert_eq!(resource.len(), 1);
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.provider")),
+            Some(Value::from("aws"))
+        );
+    }
+}
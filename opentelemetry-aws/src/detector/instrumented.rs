@@ -0,0 +1,182 @@
+//! Instrumented resource detector adapter
+//!
+//! Record how long a resource detector takes with an OpenTelemetry histogram.
+use opentelemetry::metrics::{Histogram, Meter};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::time::{Duration, Instant};
+
+const DETECTION_DURATION_METRIC: &str = "resource.detection.duration";
+const DETECTOR_KEY: &str = "detector";
+const SUCCESS_KEY: &str = "success";
+
+/// Record the duration of a wrapped [`ResourceDetector`].
+///
+/// Every [`detect`] records one observation, in seconds, on the `resource.detection.duration`
+/// histogram created from the given [`Meter`]. Observations carry a `detector` attribute holding
+/// the detector name and a `success` attribute that is `false` when the detected resource is
+/// empty.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::global;
+/// use opentelemetry_aws::detector::{InstrumentedResourceDetector, StaticResourceDetector};
+/// use opentelemetry::KeyValue;
+/// use opentelemetry_sdk::Resource;
+/// use std::time::Duration;
+///
+/// let meter = global::meter("opentelemetry-aws");
+/// let detector = InstrumentedResourceDetector::new(
+///     StaticResourceDetector::new(vec![KeyValue::new("cloud.provider", "aws")]),
+///     "static",
+///     &meter,
+/// );
+/// let resource = Resource::from_detectors(Duration::from_secs(1), vec![Box::new(detector)]);
+/// ```
+///
+/// [`detect`]: ResourceDetector::detect
+#[derive(Debug)]
+pub struct InstrumentedResourceDetector<D> {
+    detector: D,
+    name: String,
+    duration: Histogram<f64>,
+}
+
+impl<D: ResourceDetector> InstrumentedResourceDetector<D> {
+    /// Wraps `detector`, recording its durations under `name` with instruments from `meter`.
+    pub fn new<T: Into<String>>(detector: D, name: T, meter: &Meter) -> Self {
+        InstrumentedResourceDetector {
+            detector,
+            name: name.into(),
+            duration: meter
+                .f64_histogram(DETECTION_DURATION_METRIC)
+                .with_description("Duration of resource detection.")
+                .with_unit("s")
+                .init(),
+        }
+    }
+}
+
+impl<D: ResourceDetector> ResourceDetector for InstrumentedResourceDetector<D> {
+    fn detect(&self, timeout: Duration) -> Resource {
+        let start = Instant::now();
+        let resource = self.detector.detect(timeout);
+
+        self.duration.record(
+            start.elapsed().as_secs_f64(),
+            &[
+                KeyValue::new(DETECTOR_KEY, self.name.clone()),
+                KeyValue::new(SUCCESS_KEY, !resource.is_empty()),
+            ],
+        );
+
+        resource
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::StaticResourceDetector;
+    use opentelemetry::metrics::MeterProvider as _;
+    use opentelemetry::Value;
+    use opentelemetry_sdk::metrics::data::{self, ResourceMetrics, Temporality};
+    use opentelemetry_sdk::metrics::reader::{
+        AggregationSelector, MetricReader, TemporalitySelector,
+    };
+    use opentelemetry_sdk::metrics::{
+        Aggregation, InstrumentKind, ManualReader, Pipeline, SdkMeterProvider,
+    };
+    use std::sync::{Arc, Weak};
+
+    #[derive(Clone, Debug)]
+    struct SharedReader(Arc<ManualReader>);
+
+    impl AggregationSelector for SharedReader {
+        fn aggregation(&self, kind: InstrumentKind) -> Aggregation {
+            self.0.aggregation(kind)
+        }
+    }
+
+    impl TemporalitySelector for SharedReader {
+        fn temporality(&self, kind: InstrumentKind) -> Temporality {
+            self.0.temporality(kind)
+        }
+    }
+
+    impl MetricReader for SharedReader {
+        fn register_pipeline(&self, pipeline: Weak<Pipeline>) {
+            self.0.register_pipeline(pipeline)
+        }
+
+        fn collect(&self, rm: &mut ResourceMetrics) -> opentelemetry::metrics::Result<()> {
+            self.0.collect(rm)
+        }
+
+        fn force_flush(&self) -> opentelemetry::metrics::Result<()> {
+            self.0.force_flush()
+        }
+
+        fn shutdown(&self) -> opentelemetry::metrics::Result<()> {
+            self.0.shutdown()
+        }
+    }
+
+    fn count(points: &[data::HistogramDataPoint<f64>], success: bool) -> u64 {
+        points
+            .iter()
+            .filter(|point| {
+                point
+                    .attributes
+                    .iter()
+                    .any(|kv| kv.key.as_str() == SUCCESS_KEY && kv.value == Value::from(success))
+                    && point.attributes.iter().any(|kv| {
+                        kv.key.as_str() == DETECTOR_KEY && kv.value == Value::from("static")
+                    })
+            })
+            .map(|point| point.count)
+            .sum()
+    }
+
+    #[test]
+    fn test_instrumented_resource_detector() {
+        let reader = SharedReader(Arc::new(ManualReader::builder().build()));
+        let provider = SdkMeterProvider::builder()
+            .with_reader(reader.clone())
+            .build();
+        let meter = provider.meter("test");
+
+        let detector = InstrumentedResourceDetector::new(
+            StaticResourceDetector::new(vec![KeyValue::new("cloud.provider", "aws")]),
+            "static",
+            &meter,
+        );
+        let empty_detector = InstrumentedResourceDetector::new(
+            StaticResourceDetector::new(vec![]),
+            "static",
+            &meter,
+        );
+
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert!(!detector.detect(Duration::from_secs(0)).is_empty());
+        assert!(empty_detector.detect(Duration::from_secs(0)).is_empty());
+
+        let mut rm = ResourceMetrics {
+            resource: Resource::empty(),
+            scope_metrics: vec![],
+        };
+        reader.collect(&mut rm).unwrap();
+
+        let metric = &rm.scope_metrics[0].metrics[0];
+        assert_eq!(metric.name, DETECTION_DURATION_METRIC);
+        let histogram = metric
+            .data
+            .as_any()
+            .downcast_ref::<data::Histogram<f64>>()
+            .unwrap();
+        assert_eq!(count(&histogram.data_points, true), 2);
+        assert_eq!(count(&histogram.data_points, false), 1);
+    }
+}
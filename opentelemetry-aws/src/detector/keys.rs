@@ -0,0 +1,82 @@
+//! Attribute keys emitted by the AWS detectors that aren't defined by the OpenTelemetry semantic
+//! conventions.
+
+/// The ID of the AWS App Runner service, shared by all of its instances.
+pub const AWS_APPRUNNER_SERVICE_ID: &str = "aws.apprunner.service.id";
+
+/// The ID of the AWS Batch job.
+pub const AWS_BATCH_JOB_ID: &str = "aws.batch.job.id";
+
+/// The attempt number of the AWS Batch job.
+pub const AWS_BATCH_JOB_ATTEMPT: &str = "aws.batch.job.attempt";
+
+/// The name of the compute environment running the AWS Batch job.
+pub const AWS_BATCH_COMPUTE_ENVIRONMENT: &str = "aws.batch.compute_environment";
+
+#[cfg(all(
+    test,
+    any(feature = "detector-aws-apprunner", feature = "detector-aws-batch")
+))]
+mod tests {
+    use super::*;
+    use opentelemetry_sdk::resource::ResourceDetector;
+    use opentelemetry_sdk::Resource;
+    use opentelemetry_semantic_conventions::resource::{
+        CLOUD_PLATFORM, CLOUD_PROVIDER, CLOUD_REGION,
+    };
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    /// Keys of `resource` that aren't defined by the semantic conventions.
+    fn custom_keys(resource: &Resource) -> HashSet<&str> {
+        resource
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .filter(|key| ![CLOUD_PLATFORM, CLOUD_PROVIDER, CLOUD_REGION].contains(key))
+            .collect()
+    }
+
+    #[cfg(feature = "detector-aws-apprunner")]
+    #[test]
+    fn test_keys_match_apprunner_resource_detector() {
+        temp_env::with_var(
+            "AWS_APP_RUNNER_SERVICE_ID",
+            Some("8fe1e10304f84fd2b0df550fe98a71fa"),
+            || {
+                let resource =
+                    crate::detector::AppRunnerResourceDetector.detect(Duration::from_secs(0));
+                assert_eq!(
+                    custom_keys(&resource),
+                    HashSet::from([AWS_APPRUNNER_SERVICE_ID])
+                );
+            },
+        );
+    }
+
+    #[cfg(feature = "detector-aws-batch")]
+    #[test]
+    fn test_keys_match_batch_resource_detector() {
+        temp_env::with_vars(
+            [
+                (
+                    "AWS_BATCH_JOB_ID",
+                    Some("a4d6c728-8ee8-4053-9b0c-2e1dc1c4e2c4"),
+                ),
+                ("AWS_BATCH_JOB_ATTEMPT", Some("2")),
+                ("AWS_BATCH_CE_NAME", Some("my-compute-environment")),
+            ],
+            || {
+                let resource =
+                    crate::detector::BatchResourceDetector.detect(Duration::from_secs(0));
+                assert_eq!(
+                    custom_keys(&resource),
+                    HashSet::from([
+                        AWS_BATCH_JOB_ID,
+                        AWS_BATCH_JOB_ATTEMPT,
+                        AWS_BATCH_COMPUTE_ENVIRONMENT
+                    ])
+                );
+            },
+        );
+    }
+}
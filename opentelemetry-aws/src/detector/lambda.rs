@@ -0,0 +1,141 @@
+//! AWS Lambda resource detector
+//!
+//! Detect the Lambda function instance from the environment variables of the execution environment.
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use opentelemetry_semantic_conventions::resource::{
+    CLOUD_PLATFORM, CLOUD_PROVIDER, FAAS_INSTANCE, FAAS_MAX_MEMORY,
+};
+use std::env;
+use std::time::Duration;
+
+const AWS_LAMBDA_FUNCTION_NAME_ENV_VAR: &str = "AWS_LAMBDA_FUNCTION_NAME";
+const AWS_LAMBDA_FUNCTION_MEMORY_SIZE_ENV_VAR: &str = "AWS_LAMBDA_FUNCTION_MEMORY_SIZE";
+const AWS_LAMBDA_LOG_STREAM_NAME_ENV_VAR: &str = "AWS_LAMBDA_LOG_STREAM_NAME";
+
+/// Detect AWS Lambda resource attributes.
+///
+/// Populates `cloud.provider`, `cloud.platform`, `faas.max_memory` and `faas.instance` from the
+/// [environment variables][lambda-env] set by Lambda. `AWS_LAMBDA_FUNCTION_MEMORY_SIZE` is given
+/// in megabytes and converted to bytes, as expected by the semantic conventions, and ignored unless
+/// it is a positive number. `faas.instance` is the log stream name, which is unique to the
+/// execution environment. Returns an empty resource when `AWS_LAMBDA_FUNCTION_NAME` isn't set.
+///
+/// [lambda-env]: https://docs.aws.amazon.com/lambda/latest/dg/configuration-envvars.html#configuration-envvars-runtime
+pub struct LambdaResourceDetector;
+
+impl ResourceDetector for LambdaResourceDetector {
+    fn detect(&self, _timeout: Duration) -> Resource {
+        let function_name = env::var(AWS_LAMBDA_FUNCTION_NAME_ENV_VAR).unwrap_or_default();
+        if function_name.is_empty() {
+            return Resource::empty();
+        }
+
+        let mut attributes = vec![
+            KeyValue::new(CLOUD_PROVIDER, "aws"),
+            KeyValue::new(CLOUD_PLATFORM, "aws_lambda"),
+        ];
+
+        if let Some(memory_size) = env::var(AWS_LAMBDA_FUNCTION_MEMORY_SIZE_ENV_VAR)
+            .ok()
+            .and_then(|memory_size| memory_size.parse::<u32>().ok())
+            .filter(|memory_size| *memory_size > 0)
+        {
+            attributes.push(KeyValue::new(
+                FAAS_MAX_MEMORY,
+                i64::from(memory_size) * 1024 * 1024,
+            ));
+        }
+
+        if let Some(log_stream_name) = env::var(AWS_LAMBDA_LOG_STREAM_NAME_ENV_VAR)
+            .ok()
+            .filter(|log_stream_name| !log_stream_name.is_empty())
+        {
+            attributes.push(KeyValue::new(FAAS_INSTANCE, log_stream_name));
+        }
+
+        Resource::new(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{Key, Value};
+
+    #[test]
+    fn test_lambda_resource_detector() {
+        temp_env::with_vars(
+            [
+                (AWS_LAMBDA_FUNCTION_NAME_ENV_VAR, Some("my-function")),
+                (AWS_LAMBDA_FUNCTION_MEMORY_SIZE_ENV_VAR, Some("128")),
+                (
+                    AWS_LAMBDA_LOG_STREAM_NAME_ENV_VAR,
+                    Some("2024/08/01/[$LATEST]8fe1e10304f84fd2b0df550fe98a71fa"),
+                ),
+            ],
+            || {
+                let resource = LambdaResourceDetector.detect(Duration::from_secs(0));
+
+                assert_eq!(resource.len(), 4);
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_PROVIDER)),
+                    Some(Value::from("aws"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(CLOUD_PLATFORM)),
+                    Some(Value::from("aws_lambda"))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(FAAS_MAX_MEMORY)),
+                    Some(Value::from(134_217_728))
+                );
+                assert_eq!(
+                    resource.get(Key::from_static_str(FAAS_INSTANCE)),
+                    Some(Value::from(
+                        "2024/08/01/[$LATEST]8fe1e10304f84fd2b0df550fe98a71fa"
+                    ))
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_lambda_resource_detector_invalid_memory_size() {
+        for memory_size in ["lots", "-128", "0", "99999999999999"] {
+            temp_env::with_vars(
+                [
+                    (AWS_LAMBDA_FUNCTION_NAME_ENV_VAR, Some("my-function")),
+                    (AWS_LAMBDA_FUNCTION_MEMORY_SIZE_ENV_VAR, Some(memory_size)),
+                    (AWS_LAMBDA_LOG_STREAM_NAME_ENV_VAR, None),
+                ],
+                || {
+                    let resource = LambdaResourceDetector.detect(Duration::from_secs(0));
+
+                    assert_eq!(resource.len(), 2);
+                    assert_eq!(resource.get(Key::from_static_str(FAAS_MAX_MEMORY)), None);
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_lambda_resource_detector_not_on_lambda() {
+        temp_env::with_vars(
+            [
+                (AWS_LAMBDA_FUNCTION_NAME_ENV_VAR, None),
+                (AWS_LAMBDA_FUNCTION_MEMORY_SIZE_ENV_VAR, Some("128")),
+                (
+                    AWS_LAMBDA_LOG_STREAM_NAME_ENV_VAR,
+                    Some("2024/08/01/[$LATEST]8fe1e10304f84fd2b0df550fe98a71fa"),
+                ),
+            ],
+            || {
+                assert!(LambdaResourceDetector
+                    .detect(Duration::from_secs(0))
+                    .is_empty());
+            },
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Resource detectors for AWS environments.
+//!
+//! All detectors and adapters in this module are `Send + Sync` as long as the detectors they wrap
+//! are, so they can be shared across threads, for example behind an `Arc`.
+#[cfg(feature = "detector-aws-apprunner")]
+mod apprunner;
+#[cfg(feature = "detector-aws-batch")]
+mod batch;
+#[cfg(feature = "detector")]
+mod caching;
+#[cfg(feature = "detector")]
+mod chain;
+#[cfg(feature = "detector")]
+mod deadline;
+#[cfg(feature = "detector-aws-env")]
+mod env;
+#[cfg(feature = "detector")]
+mod filter;
+#[cfg(all(feature = "detector", feature = "metrics"))]
+mod instrumented;
+#[cfg(feature = "detector")]
+pub mod keys;
+#[cfg(feature = "detector-aws-lambda")]
+mod lambda;
+#[cfg(feature = "detector")]
+mod remap;
+#[cfg(feature = "detector")]
+mod static_resource;
+
+#[cfg(any(feature = "detector-aws-apprunner", feature = "detector-aws-env"))]
+const AWS_REGION_ENV_VAR: &str = "AWS_REGION";
+
+#[cfg(feature = "detector-aws-apprunner")]
+pub use apprunner::AppRunnerResourceDetector;
+#[cfg(feature = "detector-aws-batch")]
+pub use batch::BatchResourceDetector;
+#[cfg(feature = "detector")]
+pub use caching::CachingDetector;
+#[cfg(feature = "detector")]
+pub use chain::DetectorChain;
+#[cfg(feature = "detector")]
+pub use deadline::WithDeadline;
+#[cfg(feature = "detector-aws-env")]
+pub use env::EnvResourceDetector;
+#[cfg(feature = "detector")]
+pub use filter::AttributeFilterDetector;
+#[cfg(all(feature = "detector", feature = "metrics"))]
+pub use instrumented::InstrumentedResourceDetector;
+#[cfg(feature = "detector-aws-lambda")]
+pub use lambda::LambdaResourceDetector;
+#[cfg(feature = "detector")]
+pub use remap::KeyRemappingDetector;
+#[cfg(feature = "detector")]
+pub use static_resource::StaticResourceDetector;
+
+#[cfg(all(test, feature = "detector"))]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_detectors_are_send_sync() {
+        #[cfg(feature = "detector-aws-apprunner")]
+        assert_send_sync::<AppRunnerResourceDetector>();
+        #[cfg(feature = "detector-aws-batch")]
+        assert_send_sync::<BatchResourceDetector>();
+        #[cfg(feature = "detector-aws-env")]
+        assert_send_sync::<EnvResourceDetector>();
+        #[cfg(feature = "detector-aws-lambda")]
+        assert_send_sync::<LambdaResourceDetector>();
+        #[cfg(feature = "metrics")]
+        assert_send_sync::<InstrumentedResourceDetector<StaticResourceDetector>>();
+        assert_send_sync::<AttributeFilterDetector<StaticResourceDetector>>();
+        assert_send_sync::<CachingDetector<StaticResourceDetector>>();
+        assert_send_sync::<DetectorChain>();
+        assert_send_sync::<KeyRemappingDetector<StaticResourceDetector>>();
+        assert_send_sync::<StaticResourceDetector>();
+        assert_send_sync::<WithDeadline<StaticResourceDetector>>();
+    }
+}
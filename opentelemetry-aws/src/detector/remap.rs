@@ -0,0 +1,153 @@
+//! Key remapping resource detector adapter
+//!
+//! Rename the attribute keys emitted by a resource detector.
+use opentelemetry::{Key, KeyValue};
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Rename the attribute keys of a wrapped [`ResourceDetector`].
+///
+/// Keys found in the remapping table are renamed in the detected resource, while the other keys
+/// pass through unchanged. This helps match the schema expected by a backend.
+///
+/// A renamed key overrides an original key of the same name. When several keys are renamed to
+/// the same key, the value of the source key that sorts first wins.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::KeyValue;
+/// use opentelemetry_aws::detector::{KeyRemappingDetector, StaticResourceDetector};
+/// use opentelemetry_sdk::resource::ResourceDetector;
+/// use std::time::Duration;
+///
+/// let detector = KeyRemappingDetector::new(StaticResourceDetector::new(vec![KeyValue::new(
+///     "host.id",
+///     "i-1234567890abcdef0",
+/// )]))
+/// .with_mapping("host.id", "aws.instance_id");
+/// let resource = detector.detect(Duration::from_secs(0));
+/// ```
+#[derive(Debug)]
+pub struct KeyRemappingDetector<D> {
+    detector: D,
+    mappings: HashMap<Key, Key>,
+}
+
+impl<D: ResourceDetector> KeyRemappingDetector<D> {
+    /// Wraps `detector`, leaving its keys unchanged until mappings are added.
+    pub fn new(detector: D) -> Self {
+        KeyRemappingDetector {
+            detector,
+            mappings: HashMap::new(),
+        }
+    }
+
+    /// Rename the `from` attribute key to `to`.
+    pub fn with_mapping<F: Into<Key>, T: Into<Key>>(mut self, from: F, to: T) -> Self {
+        self.mappings.insert(from.into(), to.into());
+        self
+    }
+}
+
+impl<D: ResourceDetector> ResourceDetector for KeyRemappingDetector<D> {
+    fn detect(&self, timeout: Duration) -> Resource {
+        let resource = self.detector.detect(timeout);
+        if self.mappings.is_empty() {
+            return resource;
+        }
+
+        let mut renamed = resource
+            .iter()
+            .filter_map(|(key, value)| {
+                self.mappings
+                    .get(key)
+                    .map(|target| (key.clone(), target.clone(), value.clone()))
+            })
+            .collect::<Vec<_>>();
+        // `Resource::new` keeps the last duplicate, so order the pairs explicitly: original keys
+        // first, then renamed keys with the first sorted source last.
+        renamed.sort_by(|(a, _, _), (b, _, _)| b.as_str().cmp(a.as_str()));
+
+        let attributes = resource
+            .iter()
+            .filter(|(key, _)| !self.mappings.contains_key(*key))
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .chain(
+                renamed
+                    .into_iter()
+                    .map(|(_, target, value)| KeyValue::new(target, value)),
+            )
+            .collect::<Vec<_>>();
+
+        match resource.schema_url() {
+            Some(schema_url) => Resource::from_schema_url(attributes, schema_url.to_owned()),
+            None => Resource::new(attributes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::StaticResourceDetector;
+    use opentelemetry::Value;
+
+    #[test]
+    fn test_key_remapping_detector() {
+        let detector = KeyRemappingDetector::new(StaticResourceDetector::new(vec![
+            KeyValue::new("cloud.provider", "aws"),
+            KeyValue::new("cloud.region", "eu-west-1"),
+            KeyValue::new("host.id", "i-1234567890abcdef0"),
+        ]))
+        .with_mapping("host.id", "aws.instance_id")
+        .with_mapping("cloud.region", "aws.region");
+
+        let resource = detector.detect(Duration::from_secs(0));
+
+        assert_eq!(resource.len(), 3);
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.provider")),
+            Some(Value::from("aws"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("aws.region")),
+            Some(Value::from("eu-west-1"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("aws.instance_id")),
+            Some(Value::from("i-1234567890abcdef0"))
+        );
+        assert_eq!(resource.get(Key::from_static_str("cloud.region")), None);
+        assert_eq!(resource.get(Key::from_static_str("host.id")), None);
+    }
+
+    #[test]
+    fn test_key_remapping_detector_collisions() {
+        let detector = KeyRemappingDetector::new(StaticResourceDetector::new(vec![
+            KeyValue::new("cloud.region", "eu-west-1"),
+            KeyValue::new("host.id", "i-1234567890abcdef0"),
+            KeyValue::new("host.name", "ip-10-0-0-1"),
+            KeyValue::new("service.name", "checkout"),
+        ]))
+        .with_mapping("host.id", "cloud.region")
+        .with_mapping("host.name", "aws.host")
+        .with_mapping("service.name", "aws.host");
+
+        for _ in 0..10 {
+            let resource = detector.detect(Duration::from_secs(0));
+
+            assert_eq!(resource.len(), 2);
+            assert_eq!(
+                resource.get(Key::from_static_str("cloud.region")),
+                Some(Value::from("i-1234567890abcdef0"))
+            );
+            assert_eq!(
+                resource.get(Key::from_static_str("aws.host")),
+                Some(Value::from("ip-10-0-0-1"))
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Static resource detector
+//!
+//! Provide a fixed set of AWS-like attributes without any network call.
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::resource::ResourceDetector;
+use opentelemetry_sdk::Resource;
+use std::time::Duration;
+
+/// Detect a fixed set of resource attributes.
+///
+/// Returns the attributes it was constructed with on every [`detect`], ignoring the timeout. This
+/// is handy during local development, where neither the AWS metadata services nor the AWS
+/// environment variables are available.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::KeyValue;
+/// use opentelemetry_aws::detector::StaticResourceDetector;
+/// use opentelemetry_sdk::Resource;
+/// use std::time::Duration;
+///
+/// let detector = StaticResourceDetector::new(vec![
+///     KeyValue::new("cloud.provider", "aws"),
+///     KeyValue::new("cloud.region", "eu-west-1"),
+/// ]);
+/// let resource = Resource::from_detectors(Duration::from_secs(0), vec![Box::new(detector)]);
+/// ```
+///
+/// [`detect`]: ResourceDetector::detect
+#[derive(Clone, Debug)]
+pub struct StaticResourceDetector {
+    resource: Resource,
+}
+
+impl StaticResourceDetector {
+    /// Creates a detector returning `attributes`.
+    pub fn new<T: IntoIterator<Item = KeyValue>>(attributes: T) -> Self {
+        StaticResourceDetector {
+            resource: Resource::new(attributes),
+        }
+    }
+}
+
+impl ResourceDetector for StaticResourceDetector {
+    fn detect(&self, _timeout: Duration) -> Resource {
+        self.resource.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{Key, Value};
+
+    #[test]
+    fn test_static_resource_detector() {
+        let detector = StaticResourceDetector::new(vec![
+            KeyValue::new("cloud.provider", "aws"),
+            KeyValue::new("cloud.region", "eu-west-1"),
+            KeyValue::new("host.id", "i-1234567890abcdef0"),
+        ]);
+
+        let resource = detector.detect(Duration::from_secs(0));
+
+        assert_eq!(resource.len(), 3);
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.provider")),
+            Some(Value::from("aws"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("cloud.region")),
+            Some(Value::from("eu-west-1"))
+        );
+        assert_eq!(
+            resource.get(Key::from_static_str("host.id")),
+            Some(Value::from("i-1234567890abcdef0"))
+        );
+        assert_eq!(detector.detect(Duration::from_secs(10)), resource);
+    }
+}
@@ -1 +1,3 @@
+pub mod detector;
+pub mod metrics;
 pub mod trace;
@@ -0,0 +1,81 @@
+//! Helpers for exporting metrics to CloudWatch using the [embedded metric format][emf].
+//!
+//! [emf]: https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CloudWatch_Embedded_Metric_Format_Specification.html
+use opentelemetry::{Key, KeyValue};
+use opentelemetry_sdk::Resource;
+use opentelemetry_semantic_conventions::resource::{
+    CLOUD_ACCOUNT_ID, CLOUD_PROVIDER, CLOUD_REGION,
+};
+
+const EMF_DIMENSION_KEYS: [&str; 3] = [CLOUD_PROVIDER, CLOUD_REGION, CLOUD_ACCOUNT_ID];
+
+/// Select the standard AWS dimensions from a detected `Resource`.
+///
+/// Returns a `Resource` holding only the `cloud.provider`, `cloud.region` and `cloud.account.id`
+/// attributes of `resource`, suitable for mapping to CloudWatch EMF dimensions. Attributes missing
+/// from `resource` are left out.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::KeyValue;
+/// use opentelemetry_aws::metrics::emf_dimensions;
+/// use opentelemetry_sdk::Resource;
+///
+/// let resource = Resource::new(vec![
+///     KeyValue::new("cloud.provider", "aws"),
+///     KeyValue::new("cloud.region", "eu-west-1"),
+///     KeyValue::new("host.id", "i-1234567890abcdef0"),
+/// ]);
+/// let dimensions = emf_dimensions(&resource);
+/// assert_eq!(dimensions.len(), 2);
+/// ```
+pub fn emf_dimensions(resource: &Resource) -> Resource {
+    Resource::new(EMF_DIMENSION_KEYS.iter().filter_map(|key| {
+        let key = Key::from_static_str(key);
+        resource
+            .get(key.clone())
+            .map(|value| KeyValue::new(key, value))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+    use opentelemetry_semantic_conventions::resource::HOST_ID;
+
+    #[test]
+    fn test_emf_dimensions() {
+        let resource = Resource::new(vec![
+            KeyValue::new(CLOUD_PROVIDER, "aws"),
+            KeyValue::new(CLOUD_REGION, "eu-west-1"),
+            KeyValue::new(CLOUD_ACCOUNT_ID, "123456789012"),
+            KeyValue::new(HOST_ID, "i-1234567890abcdef0"),
+        ]);
+
+        let dimensions = emf_dimensions(&resource);
+
+        assert_eq!(dimensions.len(), 3);
+        assert_eq!(
+            dimensions.get(Key::from_static_str(CLOUD_PROVIDER)),
+            Some(Value::from("aws"))
+        );
+        assert_eq!(
+            dimensions.get(Key::from_static_str(CLOUD_REGION)),
+            Some(Value::from("eu-west-1"))
+        );
+        assert_eq!(
+            dimensions.get(Key::from_static_str(CLOUD_ACCOUNT_ID)),
+            Some(Value::from("123456789012"))
+        );
+        assert_eq!(dimensions.get(Key::from_static_str(HOST_ID)), None);
+    }
+
+    #[test]
+    fn test_emf_dimensions_empty() {
+        let resource = Resource::new(vec![KeyValue::new(HOST_ID, "i-1234567890abcdef0")]);
+
+        assert!(emf_dimensions(&resource).is_empty());
+    }
+}
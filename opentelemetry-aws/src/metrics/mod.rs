@@ -0,0 +1,5 @@
+#[cfg(feature = "metrics")]
+pub mod emf;
+
+#[cfg(feature = "metrics")]
+pub use emf::emf_dimensions;
@@ -2,6 +2,8 @@ use opentelemetry::trace::{SpanId, TraceId};
 use opentelemetry_sdk::trace::{IdGenerator, RandomIdGenerator};
 use std::time::{Duration, UNIX_EPOCH};
 
+const XRAY_MAX_TRACE_ID_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
 /// Generates AWS X-Ray compliant Trace and Span ids.
 ///
 /// Generates OpenTelemetry formatted `TraceId`'s and `SpanId`'s. The `TraceId`'s are generated so
@@ -62,6 +64,23 @@ impl IdGenerator for XrayIdGenerator {
     }
 }
 
+/// Checks that the epoch embedded in a `TraceId` is accepted by AWS X-Ray.
+///
+/// X-Ray rejects traces whose id embeds a timestamp older than 30 days. Returns `false` for such
+/// ids, for ids whose timestamp is in the future, and for [`TraceId::INVALID`].
+pub fn is_valid_xray_trace_id(trace_id: &TraceId) -> bool {
+    let epoch_time_seconds = (u128::from_be_bytes(trace_id.to_bytes()) >> 96) as u64;
+
+    let now_seconds: u64 = opentelemetry::time::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_secs();
+
+    *trace_id != TraceId::INVALID
+        && epoch_time_seconds <= now_seconds
+        && now_seconds - epoch_time_seconds <= XRAY_MAX_TRACE_ID_AGE.as_secs()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +111,41 @@ mod tests {
         assert!(before <= trace_time);
         assert!(after >= trace_time);
     }
+
+    #[test]
+    fn test_ids_are_random() {
+        let generator: XrayIdGenerator = XrayIdGenerator::default();
+
+        let first_trace_id = generator.new_trace_id().to_string();
+        let second_trace_id = generator.new_trace_id().to_string();
+        assert_ne!(first_trace_id[8..], second_trace_id[8..]);
+
+        let first_span_id: SpanId = generator.new_span_id();
+        let second_span_id: SpanId = generator.new_span_id();
+        assert_ne!(first_span_id, SpanId::INVALID);
+        assert_ne!(first_span_id, second_span_id);
+    }
+
+    #[test]
+    fn test_is_valid_xray_trace_id() {
+        let generator: XrayIdGenerator = XrayIdGenerator::default();
+        assert!(is_valid_xray_trace_id(&generator.new_trace_id()));
+
+        let now: u64 = opentelemetry::time::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let old_epoch = now - XRAY_MAX_TRACE_ID_AGE.as_secs() - 60;
+        let old_trace_id =
+            TraceId::from_hex(&format!("{:08x}a006649127e371903a2de979", old_epoch)).unwrap();
+        assert!(!is_valid_xray_trace_id(&old_trace_id));
+
+        let future_epoch = now + 60 * 60;
+        let future_trace_id =
+            TraceId::from_hex(&format!("{:08x}a006649127e371903a2de979", future_epoch)).unwrap();
+        assert!(!is_valid_xray_trace_id(&future_trace_id));
+
+        assert!(!is_valid_xray_trace_id(&TraceId::INVALID));
+    }
 }
@@ -0,0 +1,179 @@
+//! AWS Lambda helpers.
+use crate::trace::xray_propagator::{span_context_from_str, AWS_XRAY_TRACE_HEADER};
+use crate::trace::XrayPropagator;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::SpanContext;
+use opentelemetry::Context;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const AWS_LAMBDA_TRACE_ENV_VAR: &str = "_X_AMZN_TRACE_ID";
+
+static COLD_START: AtomicBool = AtomicBool::new(true);
+
+/// Returns `true` the first time it is called in the process, `false` afterwards.
+///
+/// A Lambda execution environment serves its first invocation right after initialization, so the
+/// first call made while handling an invocation identifies a cold start. The result is meant for
+/// the [`faas.coldstart`][faas-coldstart] span attribute.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::{global, trace::{Span, Tracer}, KeyValue};
+/// use opentelemetry_aws::trace::lambda::is_cold_start;
+///
+/// let mut span = global::tracer("lambda").start("invocation");
+/// span.set_attribute(KeyValue::new("faas.coldstart", is_cold_start()));
+/// ```
+///
+/// [faas-coldstart]: https://github.com/open-telemetry/semantic-conventions/blob/main/docs/faas/faas-spans.md
+pub fn is_cold_start() -> bool {
+    COLD_START.swap(false, Ordering::Relaxed)
+}
+
+/// Continue the trace of an X-Ray trace header extracted from a Lambda event.
+///
+/// When a Lambda is invoked through API Gateway with X-Ray tracing enabled, the
+/// [`X-Amzn-Trace-Id` header][xray-header] arrives in the event payload rather than in the
+/// invocation's headers. Returns the current `Context` with the remote span described by
+/// `trace_header` set, or the current `Context` unchanged when the header isn't valid.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::trace::TraceContextExt;
+/// use opentelemetry_aws::trace::lambda::context_from_trace_header;
+///
+/// // e.g. `event.headers["X-Amzn-Trace-Id"]` of an API Gateway proxy event
+/// let trace_header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1";
+/// let cx = context_from_trace_header(trace_header);
+/// assert!(cx.span().span_context().is_remote());
+/// ```
+///
+/// [xray-header]: https://docs.aws.amazon.com/xray/latest/devguide/xray-concepts.html#xray-concepts-tracingheader
+pub fn context_from_trace_header(trace_header: &str) -> Context {
+    XrayPropagator::new().extract(&TraceHeaderExtractor(trace_header))
+}
+
+/// Extract `SpanContext` from the AWS Lambda `_X_AMZN_TRACE_ID` environment variable
+///
+/// In AWS Lambda the active [X-Ray Trace format][xray-trace-id] string is exposed through the
+/// [`_X_AMZN_TRACE_ID`][lambda-env] environment variable rather than a request header. Returns
+/// `None` when the variable is not set or doesn't hold a valid X-Ray trace header.
+///
+/// [xray-trace-id]: https://docs.aws.amazon.com/xray/latest/devguide/xray-api-sendingdata.html#xray-api-traceids
+/// [lambda-env]: https://docs.aws.amazon.com/lambda/latest/dg/configuration-envvars.html#configuration-envvars-runtime
+pub fn span_context_from_lambda_env() -> Option<SpanContext> {
+    span_context_from_str(std::env::var(AWS_LAMBDA_TRACE_ENV_VAR).ok()?.trim())
+}
+
+struct TraceHeaderExtractor<'a>(&'a str);
+
+impl Extractor for TraceHeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        if key.eq_ignore_ascii_case(AWS_XRAY_TRACE_HEADER) {
+            Some(self.0)
+        } else {
+            None
+        }
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        vec![AWS_XRAY_TRACE_HEADER]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+
+    #[test]
+    fn test_is_cold_start() {
+        assert!(is_cold_start());
+        assert!(!is_cold_start());
+        assert!(!is_cold_start());
+    }
+
+    #[test]
+    fn test_span_context_from_lambda_env() {
+        let trace_id = TraceId::from_hex("58406520a006649127e371903a2de979").unwrap();
+        let span_id = SpanId::from_hex("4c721bf33e3caf8f").unwrap();
+
+        temp_env::with_var_unset(AWS_LAMBDA_TRACE_ENV_VAR, || {
+            assert_eq!(span_context_from_lambda_env(), None);
+        });
+
+        for (value, trace_flags) in [
+            (
+                "Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=1",
+                TraceFlags::SAMPLED,
+            ),
+            (
+                "Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=0",
+                TraceFlags::default(),
+            ),
+            (
+                "Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=?",
+                TraceFlags::new(0x02),
+            ),
+        ] {
+            temp_env::with_var(AWS_LAMBDA_TRACE_ENV_VAR, Some(value), || {
+                assert_eq!(
+                    span_context_from_lambda_env(),
+                    Some(SpanContext::new(
+                        trace_id,
+                        span_id,
+                        trace_flags,
+                        true,
+                        TraceState::default()
+                    ))
+                );
+            });
+        }
+
+        temp_env::with_var(AWS_LAMBDA_TRACE_ENV_VAR, Some("Root=1-bogus-bad"), || {
+            assert_eq!(span_context_from_lambda_env(), None);
+        });
+    }
+
+    #[test]
+    fn test_context_from_trace_header() {
+        let cx = context_from_trace_header(
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+        );
+        let span = cx.span();
+        let span_context = span.span_context();
+        assert!(span_context.is_remote());
+        assert_eq!(
+            span_context.trace_id(),
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap()
+        );
+        assert_eq!(
+            span_context.span_id(),
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap()
+        );
+        assert_eq!(span_context.trace_flags(), TraceFlags::SAMPLED);
+    }
+
+    #[test]
+    fn test_context_from_trace_header_with_lineage() {
+        let cx = context_from_trace_header(
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=0;Lineage=a87bd80c:0",
+        );
+        let span = cx.span();
+        let span_context = span.span_context();
+        assert!(span_context.is_remote());
+        assert!(!span_context.is_sampled());
+        assert_eq!(
+            span_context.trace_state().get("lineage"),
+            Some("a87bd80c:0")
+        );
+    }
+
+    #[test]
+    fn test_context_from_invalid_trace_header() {
+        let cx = context_from_trace_header("Root=1-bogus-bad");
+        assert!(!cx.span().span_context().is_valid());
+    }
+}
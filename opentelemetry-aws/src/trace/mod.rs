@@ -1,10 +1,12 @@
 #[cfg(feature = "trace")]
 pub mod id_generator;
 #[cfg(feature = "trace")]
+pub mod lambda;
+#[cfg(feature = "trace")]
 pub mod xray_propagator;
 
 #[cfg(feature = "trace")]
 pub use xray_propagator::XrayPropagator;
 
 #[cfg(feature = "trace")]
-pub use id_generator::XrayIdGenerator;
+pub use id_generator::{is_valid_xray_trace_id, XrayIdGenerator};
@@ -1,7 +1,11 @@
 //! This crate provides unofficial integration with AWS services.
 //!
 //! # Components
-//! As for now, the only components provided in this crate is AWS X-Ray propagator.
+//! This crate provides the AWS X-Ray propagator and ID generator, along with resource detectors in
+//! the [`detector`][crate::detector] module. Each AWS environment detector is enabled by its own
+//! `detector-aws-*` feature. The detector adapters, such as `CachingDetector` or `DetectorChain`,
+//! are enabled by the `detector` feature, plus the `metrics` feature for
+//! `InstrumentedResourceDetector`.
 //!
 //! ### AWS X-Ray Propagator
 //! This propagator helps propagate tracing information from upstream services to downstream services.
@@ -48,7 +52,7 @@ use opentelemetry::{
 use std::borrow::Cow;
 use std::convert::TryFrom;
 
-const AWS_XRAY_TRACE_HEADER: &str = "x-amzn-trace-id";
+pub(crate) const AWS_XRAY_TRACE_HEADER: &str = "x-amzn-trace-id";
 const AWS_XRAY_VERSION_KEY: &str = "1";
 const HEADER_PARENT_KEY: &str = "Parent";
 const HEADER_ROOT_KEY: &str = "Root";
@@ -378,4 +382,38 @@ mod tests {
             }
         }
     }
+
+    #[rustfmt::skip]
+    fn round_trip_test_data() -> Vec<(&'static str, Option<&'static str>)> {
+        vec![
+            ("Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=1", Some("Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=1")),
+            ("Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=0", Some("Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=0")),
+            ("Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f", Some("Root=1-58406520-a006649127e371903a2de979;Parent=4c721bf33e3caf8f;Sampled=?")),
+            ("Root=1-58406520-a006649127e371903a2de979;Parent=garbage;Sampled=1", None),
+            ("Root=1-bogus-bad;Parent=4c721bf33e3caf8f;Sampled=1", None),
+            ("Root=58406520a006649127e371903a2de979", None),
+        ]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let propagator = XrayPropagator::default();
+        for (header, expected) in round_trip_test_data() {
+            let extractor: HashMap<String, String> =
+                vec![(AWS_XRAY_TRACE_HEADER.to_string(), header.to_string())]
+                    .into_iter()
+                    .collect();
+            let context = propagator.extract(&extractor);
+
+            let mut injector: HashMap<String, String> = HashMap::new();
+            propagator.inject_context(&context, &mut injector);
+
+            assert_eq!(
+                injector.get(AWS_XRAY_TRACE_HEADER).map(String::as_str),
+                expected,
+                "round trip of {:?}",
+                header
+            );
+        }
+    }
 }